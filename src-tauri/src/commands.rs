@@ -1,30 +1,639 @@
+use krilla::color::rgb;
+use krilla::geom::{Size, Transform};
+use krilla::image::Image;
+use krilla::ocg::{OcgRef, OptionalContentGroup};
 use krilla::page::PageSettings;
-use krilla::Document;
+use krilla::path::{PathBuilder, Stroke};
+use krilla::metadata::{DateTime, Metadata};
+use krilla::surface::Surface;
+use krilla::{Document, SerializeSettings};
+use krilla_svg::{SurfaceExt, SvgSettings};
+use usvg::{Options, Tree};
 
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
-#[tauri::command]
-pub async fn generate_pdf(
-    file_path: String,
-) -> Result<String, String> {
+/// Points per millimetre (72 points per inch, 25.4 mm per inch).
+const PT_PER_MM: f32 = 72.0 / 25.4;
+
+/// Physical size of a standard trading-card (63×88mm), in millimetres.
+pub const STANDARD_CARD_MM: (f32, f32) = (63.0, 88.0);
+
+/// A named page-size preset.
+#[derive(Clone, Copy, serde::Deserialize)]
+pub enum PageSize {
+    A4,
+    A3,
+    UsLetter,
+    UsLegal,
+}
+
+impl PageSize {
+    /// Portrait dimensions in PDF points.
+    pub fn dimensions_pt(self) -> (f32, f32) {
+        match self {
+            PageSize::A4 => (595.0, 842.0),
+            PageSize::A3 => (842.0, 1191.0),
+            PageSize::UsLetter => (612.0, 792.0),
+            PageSize::UsLegal => (612.0, 1008.0),
+        }
+    }
+}
+
+/// Page orientation.
+#[derive(Clone, Copy, serde::Deserialize)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+/// Per-edge printable-area insets, in millimetres.
+#[derive(Clone, Copy, serde::Deserialize)]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    /// Equal margins on every edge.
+    pub fn uniform(mm: f32) -> Self {
+        Self {
+            top: mm,
+            right: mm,
+            bottom: mm,
+            left: mm,
+        }
+    }
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Margins::uniform(10.0)
+    }
+}
+
+/// Page geometry: a preset size, orientation, and margins.
+#[derive(Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct PageConfig {
+    pub size: PageSize,
+    pub orientation: Orientation,
+    pub margins: Margins,
+}
+
+impl PageConfig {
+    /// Page dimensions in points, with orientation applied.
+    pub fn size_pt(&self) -> (f32, f32) {
+        let (w, h) = self.size.dimensions_pt();
+        match self.orientation {
+            Orientation::Portrait => (w, h),
+            Orientation::Landscape => (h, w),
+        }
+    }
+}
+
+impl Default for PageConfig {
+    fn default() -> Self {
+        Self {
+            size: PageSize::A4,
+            orientation: Orientation::Portrait,
+            margins: Margins::uniform(10.0),
+        }
+    }
+}
+
+/// Where the card backs come from in duplex mode.
+#[derive(Clone, serde::Deserialize)]
+pub enum BackSide {
+    /// One back image shared by every card.
+    Shared(String),
+    /// A back image per card, parallel to the front card list.
+    PerCard(Vec<String>),
+}
+
+/// The creation date stamped into the document's metadata.
+#[derive(Clone, serde::Deserialize)]
+pub struct CreationDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Optional PDF document metadata. Generated sheets are often archived or
+/// shared, so proper identification is worthwhile.
+#[derive(Clone, Default, serde::Deserialize)]
+pub struct DocumentMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub creation_date: Option<CreationDate>,
+}
+
+/// A vector overlay (set icon, frame, watermark, …) placed at a fixed spot on
+/// every generated front page.
+#[derive(Clone, serde::Deserialize)]
+pub struct SvgOverlay {
+    pub path: String,
+    /// Top-left corner of the overlay, in millimetres from the page's top-left
+    /// origin.
+    pub x_mm: f32,
+    pub y_mm: f32,
+    /// Rendered size in millimetres.
+    pub w_mm: f32,
+    pub h_mm: f32,
+}
+
+impl SvgOverlay {
+    /// The overlay rectangle converted to PDF points: `(x, y, w, h)`.
+    fn rect_pt(&self) -> (f32, f32, f32, f32) {
+        (
+            self.x_mm * PT_PER_MM,
+            self.y_mm * PT_PER_MM,
+            self.w_mm * PT_PER_MM,
+            self.h_mm * PT_PER_MM,
+        )
+    }
+}
+
+/// Knobs controlling how a proxy sheet is imposed.
+///
+/// Every field has a sensible default, so callers (including the Tauri layer)
+/// may send only the knobs they care about.
+#[derive(Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct SheetOptions {
+    /// Physical card size in millimetres (use [`STANDARD_CARD_MM`] for TCG cards).
+    pub card_mm: (f32, f32),
+    /// Page size, orientation, and margins.
+    pub page: PageConfig,
+    /// Target print resolution, recorded in the generation log for reference.
+    pub dpi: f32,
+    /// Draw corner crop marks on the toggleable "Cut Lines" optional-content layer.
+    pub cut_lines: bool,
+    /// Put card backs on the toggleable "Card Backs" optional-content layer
+    /// (duplex mode only). When off, backs are still drawn, just not on a layer.
+    pub card_backs: bool,
+    /// Extra image area drawn beyond each card's trim box, in millimetres.
+    pub bleed_mm: f32,
+    /// Vector overlays drawn on every front page.
+    pub overlays: Vec<SvgOverlay>,
+    /// When set, emit a mirrored back sheet after every front sheet for
+    /// long-edge duplex printing.
+    pub duplex: Option<BackSide>,
+    /// Document metadata written into the PDF.
+    pub metadata: DocumentMetadata,
+    /// Compress content streams to shrink the output. On by default.
+    pub compress: bool,
+}
+
+impl Default for SheetOptions {
+    fn default() -> Self {
+        Self {
+            card_mm: STANDARD_CARD_MM,
+            page: PageConfig::default(),
+            dpi: 300.0,
+            cut_lines: true,
+            card_backs: true,
+            bleed_mm: 0.0,
+            overlays: Vec::new(),
+            duplex: None,
+            metadata: DocumentMetadata::default(),
+            compress: true,
+        }
+    }
+}
+
+/// A card face loaded from disk: either a raster image or a parsed vector tree.
+///
+/// SVG assets (mana symbols, set icons, custom frames) stay vector all the way
+/// into the PDF so they remain crisp at any print DPI.
+enum CardAsset {
+    Raster(Image),
+    Svg(Tree),
+}
+
+/// Load a raster card asset and hand it to krilla as an [`Image`].
+///
+/// The format is sniffed from the bytes rather than the extension, so a
+/// mislabelled file still renders as long as the container is recognised.
+fn load_image(path: &str) -> Result<Image, String> {
+    let data = std::fs::read(path).map_err(|e| format!("failed to read '{path}': {e}"))?;
+    let data = Arc::new(data);
+    Image::from_png(data.clone())
+        .or_else(|| Image::from_jpeg(data.clone()))
+        .or_else(|| Image::from_gif(data.clone()))
+        .ok_or_else(|| format!("unsupported image format: '{path}'"))
+}
+
+/// Load a card face, parsing `.svg` inputs as vector trees and everything else
+/// as a raster image.
+fn load_asset(path: &str) -> Result<CardAsset, String> {
+    if path.to_ascii_lowercase().ends_with(".svg") {
+        let data = std::fs::read(path).map_err(|e| format!("failed to read '{path}': {e}"))?;
+        let tree = Tree::from_data(&data, &Options::default())
+            .map_err(|e| format!("failed to parse SVG '{path}': {e}"))?;
+        Ok(CardAsset::Svg(tree))
+    } else {
+        load_image(path).map(CardAsset::Raster)
+    }
+}
+
+/// Draw a card face at the current surface origin, scaled to `size`.
+///
+/// The asset is borrowed so the caller can keep it cached and reuse it; cloning
+/// an [`Image`] is cheap (it is reference-counted internally).
+fn draw_asset(surface: &mut Surface, asset: &CardAsset, size: Size) {
+    match asset {
+        CardAsset::Raster(image) => surface.draw_image(image.clone(), size),
+        CardAsset::Svg(tree) => {
+            surface.draw_svg(tree, size, SvgSettings::default());
+        }
+    }
+}
+
+/// Place an SVG overlay (set icon, frame, watermark, …) at `(x, y)` on the
+/// current page, scaled to `w`×`h` points.
+pub fn draw_svg_overlay(surface: &mut Surface, path: &str, x: f32, y: f32, w: f32, h: f32) -> Result<(), String> {
+    let data = std::fs::read(path).map_err(|e| format!("failed to read '{path}': {e}"))?;
+    let tree = Tree::from_data(&data, &Options::default())
+        .map_err(|e| format!("failed to parse SVG '{path}': {e}"))?;
+    surface.push_transform(&Transform::from_translate(x, y));
+    surface.draw_svg(&tree, Size::from_wh(w, h).unwrap(), SvgSettings::default());
+    surface.pop();
+    Ok(())
+}
+
+/// Draw outward-pointing corner ticks around the trim box whose top-left
+/// corner is `(x, y)`, marking where the card should be cut.
+fn draw_crop_marks(surface: &mut Surface, x: f32, y: f32, w: f32, h: f32, stroke: &Stroke) {
+    // ~2mm ticks, kept just outside the trim box so they survive the cut.
+    let tick = 2.0 * PT_PER_MM;
+    let corners = [(x, y), (x + w, y), (x, y + h), (x + w, y + h)];
+    for (cx, cy) in corners {
+        let hx = if cx > x { cx + tick } else { cx - tick };
+        let vy = if cy > y { cy + tick } else { cy - tick };
+
+        let mut pb = PathBuilder::new();
+        pb.move_to(cx, cy);
+        pb.line_to(hx, cy);
+        pb.move_to(cx, cy);
+        pb.line_to(cx, vy);
+        if let Some(path) = pb.finish() {
+            surface.stroke_path(&path, stroke);
+        }
+    }
+}
+
+/// Resolved grid geometry in PDF points, shared by front and back sheets.
+///
+/// `origin` is the top-left corner of slot 0's trim box and `pitch` is the
+/// slot-to-slot spacing (card size plus a gutter that absorbs the bleed), so
+/// each card's bleed meets its neighbour's exactly instead of painting over it.
+#[derive(Clone)]
+struct Grid {
+    cols: usize,
+    page_pt: (f32, f32),
+    origin: (f32, f32),
+    card: (f32, f32),
+    pitch: (f32, f32),
+    bleed: f32,
+}
+
+/// Render a single page placing each `(slot, path)` at its row-major position in
+/// the grid, optionally drawing cut marks over every occupied slot.
+///
+/// `content_layer`, when set, wraps the card images in an optional-content group
+/// (used to park card backs on a toggleable "Card Backs" layer). `overlays` are
+/// drawn on top of the cards at their fixed page positions.
+fn render_page(
+    document: &mut Document,
+    grid: &Grid,
+    slots: &[(usize, String)],
+    content_layer: Option<OcgRef>,
+    cut: Option<(OcgRef, &Stroke)>,
+    overlays: &[SvgOverlay],
+    cache: &mut HashMap<String, CardAsset>,
+) -> Result<(), String> {
+    let (page_w, page_h) = grid.page_pt;
+    let (card_w, card_h) = grid.card;
+    let (origin_x, origin_y) = grid.origin;
+    let (pitch_w, pitch_h) = grid.pitch;
+    let bleed = grid.bleed;
+
+    // Top-left of a slot's trim box.
+    let slot_xy = |slot: usize| {
+        let col = slot % grid.cols;
+        let row = slot / grid.cols;
+        (origin_x + col as f32 * pitch_w, origin_y + row as f32 * pitch_h)
+    };
+
+    let mut page = document.start_page_with(PageSettings::from_wh(page_w, page_h).unwrap());
+    let mut surface = page.surface();
+
+    if let Some(layer) = content_layer {
+        surface.push_ocg(layer);
+    }
+    for (slot, card) in slots {
+        let (x, y) = slot_xy(*slot);
 
-    // First, we create a new document. This represents a single PDF document.
-    let mut document = Document::new();
-    
-    // We can now successively add new pages by calling `start_page`, or `start_page_with`
+        // The image is grown by the bleed on every edge; the trim box stays at
+        // the card's exact physical size and the pitch keeps bleeds from
+        // overlapping neighbours. Each distinct path is decoded once and reused,
+        // so a shared back image isn't re-read for every slot.
+        if !cache.contains_key(card) {
+            cache.insert(card.clone(), load_asset(card)?);
+        }
+        surface.push_transform(&Transform::from_translate(x - bleed, y - bleed));
+        let size = Size::from_wh(card_w + 2.0 * bleed, card_h + 2.0 * bleed).unwrap();
+        draw_asset(&mut surface, &cache[card], size);
+        surface.pop();
+    }
+    if content_layer.is_some() {
+        surface.pop();
+    }
 
-    let page = document.start_page_with(PageSettings::from_wh(300.0, 600.0).unwrap());
+    if let Some((layer, stroke)) = cut {
+        surface.push_ocg(layer);
+        for (slot, _) in slots {
+            let (x, y) = slot_xy(*slot);
+            draw_crop_marks(&mut surface, x, y, card_w, card_h, stroke);
+        }
+        surface.pop();
+    }
+
+    for overlay in overlays {
+        let (x, y, w, h) = overlay.rect_pt();
+        draw_svg_overlay(&mut surface, &overlay.path, x, y, w, h)?;
+    }
+
+    surface.finish();
     page.finish();
+    Ok(())
+}
+
+/// Populate the document's metadata from `md`, skipping any fields left unset.
+fn apply_metadata(document: &mut Document, md: &DocumentMetadata) {
+    let mut metadata = Metadata::new();
+    if let Some(title) = &md.title {
+        metadata = metadata.title(title.clone());
+    }
+    if let Some(author) = &md.author {
+        metadata = metadata.authors(vec![author.clone()]);
+    }
+    if let Some(subject) = &md.subject {
+        metadata = metadata.subject(subject.clone());
+    }
+    if let Some(date) = &md.creation_date {
+        metadata = metadata.creation_date(DateTime::new(date.year).month(date.month).day(date.day));
+    }
+    document.set_metadata(metadata);
+}
+
+/// How many whole slots of `pitch` fit inside `printable`, at least one.
+fn fit_count(printable: f32, pitch: f32) -> usize {
+    ((printable / pitch).floor() as usize).max(1)
+}
+
+/// Offset of the grid's footprint once `count` slots of `pitch` are centered
+/// inside `printable`, measured from the edge at `inset`.
+fn centered_inset(inset: f32, printable: f32, count: usize, pitch: f32) -> f32 {
+    inset + (printable - count as f32 * pitch) / 2.0
+}
+
+/// Map a front-sheet slot to its back-sheet slot by reversing the column order
+/// within the row. Combined with the page-mirrored back grid, card N registers
+/// with its back after a long-edge flip.
+fn mirror_slot(i: usize, cols: usize) -> usize {
+    let col = i % cols;
+    let row = i / cols;
+    row * cols + (cols - 1 - col)
+}
+
+/// Tile `cards` into a centered, row-major grid across as many pages as needed
+/// and return the encoded PDF bytes.
+///
+/// Cards are drawn at their exact physical size (plus any bleed) so the sheet
+/// is print-accurate. Cut guides, when enabled, live on a named optional-content
+/// group so a print shop can toggle them in a viewer without regenerating. In
+/// duplex mode each front sheet is followed by a column-mirrored back sheet so
+/// backs register with fronts after a long-edge flip.
+pub fn generate_card_sheet(cards: &[String], opts: &SheetOptions) -> Result<Vec<u8>, String> {
+    let settings = SerializeSettings {
+        compress_content_streams: opts.compress,
+        ..SerializeSettings::default()
+    };
+    let mut document = Document::new_with(settings);
+    apply_metadata(&mut document, &opts.metadata);
+
+    // Only register an optional-content group once we know it will actually be
+    // used, so the PDF's OCProperties never lists a phantom empty layer.
+    let cut_layer: Option<OcgRef> = opts
+        .cut_lines
+        .then(|| document.add_ocg(OptionalContentGroup::new("Cut Lines")));
+    let backs_layer: Option<OcgRef> = (opts.duplex.is_some() && opts.card_backs)
+        .then(|| document.add_ocg(OptionalContentGroup::new("Card Backs")));
+
+    let card_w = opts.card_mm.0 * PT_PER_MM;
+    let card_h = opts.card_mm.1 * PT_PER_MM;
+    let bleed = opts.bleed_mm * PT_PER_MM;
+    // Slot pitch leaves a gutter of twice the bleed between trim boxes so adjacent
+    // cards' bleeds meet rather than overlap.
+    let pitch_w = card_w + 2.0 * bleed;
+    let pitch_h = card_h + 2.0 * bleed;
+    let (page_w, page_h) = opts.page.size_pt();
+
+    // The margins inset the printable area on each edge.
+    let m = opts.page.margins;
+    let (left, right) = (m.left * PT_PER_MM, m.right * PT_PER_MM);
+    let (top, bottom) = (m.top * PT_PER_MM, m.bottom * PT_PER_MM);
+    let printable_w = page_w - left - right;
+    let printable_h = page_h - top - bottom;
+
+    // How many whole cards fit inside the printable area, and where the
+    // resulting grid sits once it is centered within it.
+    let cols = fit_count(printable_w, pitch_w);
+    let rows = fit_count(printable_h, pitch_h);
+    let per_page = cols * rows;
+
+    // krilla uses a top-left page origin, so inset the grid from the top margin;
+    // insetting from the bottom would shift the grid by (bottom - top) under
+    // asymmetric margins. The trim box of slot 0 sits a bleed in from the
+    // centered footprint's corner.
+    let origin_x = centered_inset(left, printable_w, cols, pitch_w) + bleed;
+    let origin_y = centered_inset(top, printable_h, rows, pitch_h) + bleed;
+
+    let guide_stroke = Stroke {
+        paint: rgb::Color::black().into(),
+        width: 0.5,
+        ..Stroke::default()
+    };
+
+    let grid = Grid {
+        cols,
+        page_pt: (page_w, page_h),
+        origin: (origin_x, origin_y),
+        card: (card_w, card_h),
+        pitch: (pitch_w, pitch_h),
+        bleed,
+    };
+    let cut = cut_layer.map(|layer| (layer, &guide_stroke));
+
+    // Back sheets mirror about the *page* centerline, not the grid center, so
+    // fronts and backs still register after a long-edge flip when the
+    // horizontal margins are asymmetric. Reversing the column index within this
+    // shifted grid reproduces `page_w - (front_x + card_w)` for every slot.
+    let back_origin_x = page_w - card_w - (cols as f32 - 1.0) * pitch_w - origin_x;
+    let back_grid = Grid {
+        origin: (back_origin_x, origin_y),
+        ..grid.clone()
+    };
+
+    // Decoded assets are cached across every page so repeated faces (especially
+    // a shared card back) are read and decoded only once for the whole run.
+    let mut cache: HashMap<String, CardAsset> = HashMap::new();
 
-    // Create the PDF
-    let pdf = document.finish().unwrap();
+    for (page_idx, chunk) in cards.chunks(per_page).enumerate() {
+        let front: Vec<(usize, String)> = chunk.iter().cloned().enumerate().collect();
+        render_page(&mut document, &grid, &front, None, cut, &opts.overlays, &mut cache)?;
 
-    let path = Path::new(&file_path);
+        if let Some(backs) = &opts.duplex {
+            // Reverse the column order within each row so card N on the front
+            // lands under the correct back after a long-edge duplex flip.
+            let start = page_idx * per_page;
+            let mut back: Vec<(usize, String)> = Vec::with_capacity(chunk.len());
+            for i in 0..chunk.len() {
+                let path = match backs {
+                    BackSide::Shared(p) => p.clone(),
+                    BackSide::PerCard(list) => list
+                        .get(start + i)
+                        .cloned()
+                        .ok_or_else(|| format!("missing back image for card {}", start + i))?,
+                };
+                back.push((mirror_slot(i, cols), path));
+            }
+            render_page(&mut document, &back_grid, &back, backs_layer, cut, &[], &mut cache)?;
+        }
+    }
 
-    // Write the PDF to a file.
-    std::fs::write(path, &pdf).unwrap();
+    eprintln!(
+        "Imposed {} card(s) at {} DPI into a {cols}×{rows} grid",
+        cards.len(),
+        opts.dpi
+    );
 
+    document.finish().map_err(|e| e.to_string())
+}
+
+/// A single sheet-generation job: the cards to impose and where to write the PDF.
+#[derive(Clone, serde::Deserialize)]
+pub struct SheetJob {
+    pub cards: Vec<String>,
+    pub output_path: String,
+}
+
+/// Generate a single job's sheet and write it to disk, returning the output
+/// path on success.
+fn run_job(job: &SheetJob, opts: &SheetOptions) -> Result<String, String> {
+    let pdf = generate_card_sheet(&job.cards, opts)?;
+
+    let path = Path::new(&job.output_path);
+    std::fs::write(path, &pdf).map_err(|e| format!("failed to write '{}': {e}", path.display()))?;
     eprintln!("Saved PDF to '{}'", path.display());
 
     Ok(path.display().to_string())
-}
\ No newline at end of file
+}
+
+/// Generate several sheets concurrently, one per job, and return a result for
+/// each in input order. A failing job yields an `Err` without aborting the rest
+/// — krilla has no single-threaded-per-process restriction, so the jobs run on
+/// separate worker threads.
+pub fn generate_batch(jobs: &[SheetJob], opts: &SheetOptions) -> Vec<Result<String, String>> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = jobs
+            .iter()
+            .map(|job| scope.spawn(|| run_job(job, opts)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| Err("worker thread panicked".to_string())))
+            .collect()
+    })
+}
+
+#[tauri::command]
+pub async fn generate_pdf(
+    file_path: String,
+    cards: Vec<String>,
+    options: SheetOptions,
+) -> Result<String, String> {
+    run_job(
+        &SheetJob {
+            cards,
+            output_path: file_path,
+        },
+        &options,
+    )
+}
+
+#[tauri::command]
+pub async fn generate_batch_pdf(
+    jobs: Vec<SheetJob>,
+    options: SheetOptions,
+) -> Vec<Result<String, String>> {
+    generate_batch(&jobs, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_count_floors_and_clamps() {
+        assert_eq!(fit_count(100.0, 30.0), 3);
+        assert_eq!(fit_count(100.0, 40.0), 2);
+        // A slot wider than the printable area still yields one column.
+        assert_eq!(fit_count(10.0, 40.0), 1);
+    }
+
+    #[test]
+    fn centered_inset_centers_the_footprint() {
+        // Three 30pt slots in 100pt leaves 10pt of slack, split evenly.
+        assert_eq!(centered_inset(10.0, 100.0, 3, 30.0), 15.0);
+        // Exact fit centers flush against the inset edge.
+        assert_eq!(centered_inset(5.0, 90.0, 3, 30.0), 5.0);
+    }
+
+    #[test]
+    fn svg_overlay_converts_mm_to_points() {
+        let overlay = SvgOverlay {
+            path: "icon.svg".to_string(),
+            x_mm: 25.4,
+            y_mm: 0.0,
+            w_mm: 12.7,
+            h_mm: 25.4,
+        };
+        let (x, y, w, h) = overlay.rect_pt();
+        // 25.4mm == 1in == 72pt.
+        assert!((x - 72.0).abs() < 1e-3);
+        assert!((y - 0.0).abs() < 1e-3);
+        assert!((w - 36.0).abs() < 1e-3);
+        assert!((h - 72.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn mirror_slot_reverses_columns_per_row() {
+        // 3-wide grid: column order reverses within each row.
+        let cols = 3;
+        assert_eq!(mirror_slot(0, cols), 2);
+        assert_eq!(mirror_slot(1, cols), 1);
+        assert_eq!(mirror_slot(2, cols), 0);
+        assert_eq!(mirror_slot(3, cols), 5);
+        assert_eq!(mirror_slot(5, cols), 3);
+        // Partial last row: a lone card in the first column of its row maps to
+        // the row's last column (an empty back slot), preserving registration.
+        assert_eq!(mirror_slot(6, cols), 8);
+    }
+}